@@ -0,0 +1,226 @@
+use crate::{mock::Mock, Instant, Monotonic};
+use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+use std::time::Instant as StdInstant;
+
+/// A source of raw time readings that a [`Clock`] can be built on top of.
+///
+/// `quanta`'s own `Clock`s implement this trait over the TSC or the OS monotonic clock, and
+/// it's public so callers can do the same: hand [`Clock::custom`] anything that implements
+/// `ClockSource` to read time from elsewhere entirely.
+///
+/// `now` is used for normal readings. `start`/`end` bracket a measurement and exist
+/// separately so an implementation can apply extra serialization around the edges of a
+/// timed region without paying that cost on every `now` call.
+pub trait ClockSource {
+    /// Gets the current time from this source, in whatever raw units it produces.
+    fn now(&self) -> u64;
+
+    /// Gets the time at the start of a measurement.
+    ///
+    /// This may be the same as [`now`](ClockSource::now), or it may apply additional
+    /// instructions to ensure accurate ordering relative to the code being measured.
+    fn start(&self) -> u64;
+
+    /// Gets the time at the end of a measurement.
+    ///
+    /// This may be the same as [`now`](ClockSource::now), or it may apply additional
+    /// instructions to ensure accurate ordering relative to the code being measured.
+    fn end(&self) -> u64;
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+struct OsClock {
+    epoch: StdInstant,
+    // Nanoseconds since the Unix epoch at the moment `epoch` was taken.
+    unix_base: u64,
+}
+
+#[cfg(feature = "std")]
+impl OsClock {
+    fn new() -> Self {
+        let unix_base = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Self {
+            epoch: StdInstant::now(),
+            unix_base,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ClockSource for OsClock {
+    fn now(&self) -> u64 {
+        self.unix_base
+            + StdInstant::now()
+                .duration_since(self.epoch)
+                .as_nanos() as u64
+    }
+
+    fn start(&self) -> u64 {
+        self.now()
+    }
+
+    fn end(&self) -> u64 {
+        self.now()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Clone, Copy)]
+struct DriverClock;
+
+#[cfg(not(feature = "std"))]
+impl ClockSource for DriverClock {
+    fn now(&self) -> u64 {
+        crate::driver::now()
+    }
+
+    fn start(&self) -> u64 {
+        self.now()
+    }
+
+    fn end(&self) -> u64 {
+        self.now()
+    }
+}
+
+/// A clock for reading the current time, backed by a pluggable [`ClockSource`].
+///
+/// By default, `Clock` reads from the operating system's monotonic clock, but any source of
+/// raw time readings can be plugged in via [`Clock::custom`].
+#[derive(Clone)]
+pub struct Clock {
+    source: Arc<dyn ClockSource + Send + Sync>,
+}
+
+impl Clock {
+    /// Creates a new `Clock` backed by the operating system's monotonic clock.
+    #[cfg(feature = "std")]
+    pub fn new() -> Clock {
+        Self::custom(OsClock::new())
+    }
+
+    /// Creates a new `Clock` backed by the registered [`Driver`](crate::Driver).
+    ///
+    /// Without `std`, there is no OS or TSC to read directly, so every `Clock` is backed by
+    /// whichever [`Driver`](crate::Driver) the binary has registered -- see
+    /// [`quanta_time_driver!`](crate::quanta_time_driver).
+    #[cfg(not(feature = "std"))]
+    pub fn new() -> Clock {
+        Self::custom(DriverClock)
+    }
+
+    /// Creates a new `Clock` backed by a custom [`ClockSource`].
+    ///
+    /// Use this to plug in any time source `quanta` doesn't know about out of the box.
+    pub fn custom<S: ClockSource + Send + Sync + 'static>(source: S) -> Clock {
+        Clock {
+            source: Arc::new(source),
+        }
+    }
+
+    /// Creates a mocked clock and its paired [`Mock`] handle.
+    ///
+    /// This can be used to allow for a test to mock out the time while allowing for multiple
+    /// callers, in multiple threads, to use the same reference time.
+    pub fn mock() -> (Clock, Mock) {
+        let mock = Mock::new();
+        let clock = Clock::custom(mock.clone());
+        (clock, mock)
+    }
+
+    /// Gets the current time, scaled to reference time.
+    ///
+    /// A `Clock` from [`Clock::custom`] or [`Clock::mock`], or one backed by the registered
+    /// [`Driver`](crate::Driver) without `std`, returns its source's reading as-is, unscaled.
+    pub fn now(&self) -> Instant {
+        Instant(self.source.now())
+    }
+
+    /// Gets the current monotonic time, suitable for durations and benchmarks.
+    ///
+    /// Unlike [`Clock::now`], which returns a reference-time [`Instant`] meant for wall-clock
+    /// use, this returns an opaque [`Monotonic`] value that is only guaranteed to be
+    /// non-decreasing relative to other readings from this `Clock`. Prefer this when you only
+    /// care about elapsed time.
+    pub fn raw(&self) -> Monotonic {
+        Monotonic(self.source.now())
+    }
+
+    /// Alias for [`Clock::raw`].
+    pub fn now_monotonic(&self) -> Monotonic {
+        self.raw()
+    }
+
+    /// Gets the raw time at the start of a measurement.
+    pub fn start(&self) -> u64 {
+        self.source.start()
+    }
+
+    /// Gets the raw time at the end of a measurement.
+    pub fn end(&self) -> u64 {
+        self.source.end()
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Clock::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    struct Fake {
+        now: AtomicU64,
+        starts: AtomicU64,
+        ends: AtomicU64,
+    }
+
+    impl ClockSource for Fake {
+        fn now(&self) -> u64 {
+            self.now.load(Ordering::Acquire)
+        }
+
+        fn start(&self) -> u64 {
+            self.starts.fetch_add(1, Ordering::AcqRel)
+        }
+
+        fn end(&self) -> u64 {
+            self.ends.fetch_add(1, Ordering::AcqRel)
+        }
+    }
+
+    #[test]
+    fn custom_routes_readings_through_the_supplied_source() {
+        let fake = Fake {
+            now: AtomicU64::new(1_234),
+            starts: AtomicU64::new(10),
+            ends: AtomicU64::new(20),
+        };
+        let clock = Clock::custom(fake);
+
+        assert_eq!(clock.now().as_u64(), 1_234);
+        assert_eq!(clock.raw().0, 1_234);
+        assert_eq!(clock.start(), 10);
+        assert_eq!(clock.end(), 20);
+    }
+
+    #[test]
+    fn mock_routes_through_the_paired_mock() {
+        let (clock, mock) = Clock::mock();
+        mock.set(5_000u64);
+        assert_eq!(clock.now().as_u64(), 5_000);
+
+        mock.increment(1_000u64);
+        assert_eq!(clock.raw().0, 6_000);
+    }
+}
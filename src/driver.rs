@@ -0,0 +1,91 @@
+//! A pluggable time source for `#![no_std]` targets.
+//!
+//! On `std` targets, [`Clock`](crate::Clock) reaches the OS or TSC directly and this module
+//! mostly stays out of the way. Without `std`, though, there is no portable way to read a
+//! clock, so `quanta` instead asks the binary to register one: exactly one crate in the final
+//! link must provide a [`Driver`], in the style of `embassy-time`'s own driver registration.
+//! The `std` feature (on by default) registers a driver backed by [`std::time::Instant`]
+//! automatically; disable it and call [`quanta_time_driver!`] from your own crate to supply a
+//! hardware timer instead.
+
+/// A source of raw time ticks for a registered [`Driver`] implementation.
+///
+/// Implementations should return ticks at a fixed, consistent resolution (nanoseconds),
+/// non-decreasing for the lifetime of the program.
+pub trait Driver {
+    /// Returns the current time, in nanosecond ticks.
+    fn now(&self) -> u64;
+}
+
+extern "Rust" {
+    // Defined by whichever crate registers a driver via `quanta_time_driver!`. Exactly one
+    // definition must exist in the final binary -- the `std` feature provides one, so
+    // `#![no_std]` users building with default features disabled must supply their own.
+    fn __quanta_driver_now() -> u64;
+}
+
+/// Registers a [`Driver`] as the process-wide time source.
+///
+/// This must be invoked exactly once in the final binary. `quanta`'s `std` feature registers
+/// a driver automatically; disable default features and invoke this macro from your own crate
+/// to back `quanta` with a hardware timer on targets without `std`.
+///
+/// ```ignore
+/// struct MyTimer;
+///
+/// impl quanta::Driver for MyTimer {
+///     fn now(&self) -> u64 {
+///         // read a hardware counter, scaled to nanoseconds
+///         0
+///     }
+/// }
+///
+/// quanta::quanta_time_driver!(MyTimer);
+/// ```
+#[macro_export]
+macro_rules! quanta_time_driver {
+    ($driver:expr) => {
+        #[no_mangle]
+        extern "Rust" fn __quanta_driver_now() -> u64 {
+            $crate::Driver::now(&$driver)
+        }
+    };
+}
+
+/// Gets the current time from the registered [`Driver`].
+pub fn now() -> u64 {
+    // Safety: exactly one definition of `__quanta_driver_now` is required to exist in the
+    // final binary, provided either by the `std` feature or by a `quanta_time_driver!`
+    // invocation; linking fails otherwise.
+    unsafe { __quanta_driver_now() }
+}
+
+#[cfg(feature = "std")]
+struct StdDriver {
+    epoch: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl Driver for StdDriver {
+    fn now(&self) -> u64 {
+        std::time::Instant::now()
+            .duration_since(self.epoch)
+            .as_nanos() as u64
+    }
+}
+
+#[cfg(feature = "std")]
+fn std_driver() -> &'static StdDriver {
+    use std::sync::OnceLock;
+
+    static DRIVER: OnceLock<StdDriver> = OnceLock::new();
+    DRIVER.get_or_init(|| StdDriver {
+        epoch: std::time::Instant::now(),
+    })
+}
+
+#[cfg(feature = "std")]
+#[no_mangle]
+extern "Rust" fn __quanta_driver_now() -> u64 {
+    Driver::now(std_driver())
+}
@@ -0,0 +1,52 @@
+//! Integration with the [`governor`] rate-limiting crate.
+//!
+//! Implements `governor`'s [`Clock`](governor::clock::Clock) for [`Clock`](crate::Clock) and
+//! [`Reference`](governor::clock::Reference) for [`Instant`](crate::Instant), so a rate
+//! limiter built on `governor` can be driven by a `quanta::Clock` instead of its default
+//! OS-backed clock. Enabled via the `governor` feature.
+
+use crate::{Clock, Instant};
+use governor::{clock, nanos::Nanos};
+
+impl clock::Clock for Clock {
+    type Instant = Instant;
+
+    fn now(&self) -> Self::Instant {
+        Clock::now(self)
+    }
+}
+
+impl clock::Reference for Instant {
+    fn duration_since(&self, earlier: Self) -> Nanos {
+        self.saturating_duration_since(earlier).into()
+    }
+
+    fn saturating_sub(&self, duration: Nanos) -> Self {
+        self.checked_sub(core::time::Duration::from_nanos(duration.as_u64()))
+            .unwrap_or(Instant(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration;
+
+    #[test]
+    fn duration_since_saturates_at_zero_when_earlier_is_later() {
+        let larger = Instant(100);
+        let smaller = Instant(50);
+        assert_eq!(
+            clock::Reference::duration_since(&smaller, larger),
+            Nanos::from(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn saturating_sub_clamps_at_zero() {
+        let instant = Instant(50);
+        let result =
+            clock::Reference::saturating_sub(&instant, Nanos::from(Duration::from_nanos(100)));
+        assert_eq!(result, Instant(0));
+    }
+}
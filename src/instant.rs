@@ -1,11 +1,13 @@
 #[cfg(feature = "metrics")]
 use metrics_core::AsNanoseconds;
 
-use std::cmp::{Ord, Ordering, PartialOrd};
-use std::fmt;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
-use std::sync::atomic::Ordering::Relaxed;
-use std::time::Duration;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::time::Duration;
+
+#[cfg(feature = "std")]
+use core::sync::atomic::Ordering::Relaxed;
+
+use crate::macros::impl_duration_arithmetic;
 
 /// A point-in-time wall-clock measurement.
 ///
@@ -20,6 +22,8 @@ use std::time::Duration;
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Instant(pub(crate) u64);
 
+impl_duration_arithmetic!(Instant);
+
 impl Instant {
     /// Gets the most recent current time, scaled to reference time.
     ///
@@ -35,6 +39,11 @@ impl Instant {
     ///
     /// If a mock timer has been created on the current thread, this will return
     /// the mock timer's current timestamp, instead.
+    ///
+    /// On targets built without the `std` feature, there is no upkeep thread or per-thread
+    /// mock to consult, so this reads directly from the registered [`Driver`](crate::Driver)
+    /// instead.
+    #[cfg(feature = "std")]
     #[inline]
     pub fn recent() -> Self {
         let recent = crate::GLOBAL_RECENT.load(Relaxed);
@@ -52,73 +61,14 @@ impl Instant {
         Self(crate::Mock::recent())
     }
 
-    /// Returns the amount of time elapsed from another instant to this one.
-    ///
-    /// # Panics
-    ///
-    /// This function will panic if `earlier` is later than `self`.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use quanta::Clock;
-    /// use std::time::Duration;
-    /// use std::thread::sleep;
-    ///
-    /// let mut clock = Clock::new();
-    /// let now = clock.now();
-    /// sleep(Duration::new(1, 0));
-    /// let new_now = clock.now();
-    /// println!("{:?}", new_now.duration_since(now));
-    /// ```
-    pub fn duration_since(&self, earlier: Instant) -> Duration {
-        self.0
-            .checked_sub(earlier.0)
-            .map(Duration::from_nanos)
-            .expect("supplied instant is later than self")
-    }
-
-    /// Returns the amount of time elapsed from another instant to this one,
-    /// or `None` if that instant is earlier than this one.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use quanta::Clock;
-    /// use std::time::Duration;
-    /// use std::thread::sleep;
-    ///
-    /// let mut clock = Clock::new();
-    /// let now = clock.now();
-    /// sleep(Duration::new(1, 0));
-    /// let new_now = clock.now();
-    /// println!("{:?}", new_now.checked_duration_since(now));
-    /// println!("{:?}", now.checked_duration_since(new_now)); // None
-    /// ```
-    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
-        self.0.checked_sub(earlier.0).map(Duration::from_nanos)
-    }
-
-    /// Returns the amount of time elapsed from another instant to this one,
-    /// or zero duration if that instant is earlier than this one.
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// use quanta::Clock;
-    /// use std::time::Duration;
-    /// use std::thread::sleep;
+    /// Gets the most recent current time, scaled to reference time.
     ///
-    /// let mut clock = Clock::new();
-    /// let now = clock.now();
-    /// sleep(Duration::new(1, 0));
-    /// let new_now = clock.now();
-    /// println!("{:?}", new_now.saturating_duration_since(now));
-    /// println!("{:?}", now.saturating_duration_since(new_now)); // 0ns
-    /// ```
-    pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
-        self.checked_duration_since(earlier)
-            .unwrap_or_else(|| Duration::new(0, 0))
+    /// Without `std` there is no upkeep thread and no per-thread mock, so this always reads
+    /// straight from the registered [`Driver`](crate::Driver).
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub fn recent() -> Self {
+        Self(crate::driver::now())
     }
 
     /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be represented as
@@ -190,24 +140,6 @@ impl Sub<Instant> for Instant {
     }
 }
 
-impl PartialOrd for Instant {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for Instant {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.0.cmp(&other.0)
-    }
-}
-
-impl fmt::Debug for Instant {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
-}
-
 #[cfg(feature = "metrics")]
 impl AsNanoseconds for Instant {
     fn as_nanos(&self) -> u64 {
@@ -215,6 +147,20 @@ impl AsNanoseconds for Instant {
     }
 }
 
+#[cfg(feature = "governor")]
+impl Add<governor::nanos::Nanos> for Instant {
+    type Output = Instant;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure.
+    fn add(self, other: governor::nanos::Nanos) -> Instant {
+        self.checked_add(Duration::from_nanos(other.as_u64()))
+            .expect("overflow when adding duration to instant")
+    }
+}
+
 #[cfg(feature = "prost")]
 impl Into<prost_types::Timestamp> for Instant {
     fn into(self) -> prost_types::Timestamp {
@@ -0,0 +1,56 @@
+// Shared arithmetic for the crate's point-in-time types (`Instant`, `Monotonic`). Both wrap a
+// single `u64` of nanoseconds and differ only in what that value means, so the duration math,
+// ordering, and `Debug` formatting built on top of it are identical -- generate them once here
+// instead of hand-copying the same impls for every new time type.
+macro_rules! impl_duration_arithmetic {
+    ($ty:ident) => {
+        impl $ty {
+            /// Returns the amount of time elapsed from another measurement to this one.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `earlier` is later than `self`.
+            pub fn duration_since(&self, earlier: $ty) -> core::time::Duration {
+                self.0
+                    .checked_sub(earlier.0)
+                    .map(core::time::Duration::from_nanos)
+                    .expect("supplied instant is later than self")
+            }
+
+            /// Returns the amount of time elapsed from another measurement to this one, or
+            /// `None` if that one is later than this one.
+            pub fn checked_duration_since(&self, earlier: $ty) -> Option<core::time::Duration> {
+                self.0
+                    .checked_sub(earlier.0)
+                    .map(core::time::Duration::from_nanos)
+            }
+
+            /// Returns the amount of time elapsed from another measurement to this one, or a
+            /// zero duration if that one is later than this one.
+            pub fn saturating_duration_since(&self, earlier: $ty) -> core::time::Duration {
+                self.checked_duration_since(earlier)
+                    .unwrap_or_else(|| core::time::Duration::new(0, 0))
+            }
+        }
+
+        impl core::cmp::PartialOrd for $ty {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::cmp::Ord for $ty {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl core::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_duration_arithmetic;
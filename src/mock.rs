@@ -1,11 +1,11 @@
 #![allow(dead_code)]
 use crate::ClockSource;
 use atomic_shim::AtomicU64;
-use std::{
-    sync::{atomic::Ordering, Arc},
-    time::Duration,
-    cell::RefCell;
-};
+use alloc::sync::Arc;
+use core::{sync::atomic::Ordering, time::Duration};
+
+#[cfg(feature = "std")]
+use std::{cell::RefCell, sync::Mutex};
 
 /// Type which can be converted into a nanosecond representation.
 ///
@@ -41,23 +41,70 @@ pub struct Mock {
     offset: Arc<AtomicU64>,
 }
 
+#[cfg(feature = "std")]
 thread_local! {
-    static CURRENT_MOCK: RefCell<Arc<AtomicU64>> = RefCell::new(Arc::new(AtomicU64::new(0)));
+    static CURRENT_MOCK: RefCell<Option<Arc<AtomicU64>>> = RefCell::new(None);
 }
 
+// Process-wide fallback consulted by `Mock::recent` when the calling thread has no
+// thread-local mock installed. This lets a single `Mock` drive `Instant::recent()` from
+// every thread -- the upkeep thread, spawned workers, and so on -- rather than only the
+// thread that created it. Both the thread-local and this fallback are `std`-only: without
+// `std`, `Instant::recent()` reads the registered `Driver` and consults no `Mock`.
+#[cfg(feature = "std")]
+static GLOBAL_MOCK: Mutex<Option<Arc<AtomicU64>>> = Mutex::new(None);
+
 impl Mock {
     pub(crate) fn new() -> Self {
         let offset = Arc::new(AtomicU64::new(0));
-        CURRENT_MOCK.with(|current| *current.borrow_mut() = offset.clone());
+        #[cfg(feature = "std")]
+        CURRENT_MOCK.with(|current| *current.borrow_mut() = Some(offset.clone()));
         Self { offset }
     }
 
     // Don't ever inline the thread-local access into `Instant::recent`, which
     // should be tiny when not using mocks...
+    #[cfg(feature = "std")]
     #[inline(never)]
     #[cold]
     pub(crate) fn recent() -> u64 {
-        CURRENT_MOCK.try_with(|cur| cur.borrow().load(Ordering::Acquire)).unwrap_or(0)
+        let local = CURRENT_MOCK.try_with(|cur| {
+            cur.borrow()
+                .as_ref()
+                .map(|offset| offset.load(Ordering::Acquire))
+        });
+
+        // A thread-local mock exists (even if its value happens to be zero) takes precedence
+        // over the global fallback -- otherwise a thread whose own mock is legitimately at
+        // time zero would silently observe some *other* thread's globally-installed mock.
+        if let Ok(Some(value)) = local {
+            return value;
+        }
+
+        GLOBAL_MOCK
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|offset| offset.load(Ordering::Acquire))
+            .unwrap_or(0)
+    }
+
+    /// Installs this `Mock` as the process-wide time source.
+    ///
+    /// Once installed, [`Instant::recent`](crate::Instant::recent) will report this `Mock`'s
+    /// current value from *any* thread, not just the one that created it -- useful when the
+    /// code under test reads the time from a worker thread or the upkeep thread. The
+    /// thread-local fast path used by [`Clock::mock`](crate::Clock::mock) still takes
+    /// precedence on the creating thread.
+    #[cfg(feature = "std")]
+    pub fn install_global(&self) {
+        *GLOBAL_MOCK.lock().unwrap() = Some(self.offset.clone());
+    }
+
+    /// Removes this (or any other) `Mock` as the process-wide time source.
+    #[cfg(feature = "std")]
+    pub fn uninstall_global(&self) {
+        *GLOBAL_MOCK.lock().unwrap() = None;
     }
 
     /// Increments the time by the given amount.
@@ -72,6 +119,19 @@ impl Mock {
             .fetch_sub(amount.into_nanos(), Ordering::Release);
     }
 
+    /// Sets the time to the given absolute value.
+    ///
+    /// Unlike [`increment`](Mock::increment) and [`decrement`](Mock::decrement), which adjust the
+    /// time relative to its current value, this overwrites it outright.
+    pub fn set<N: IntoNanoseconds>(&self, value: N) {
+        self.offset.store(value.into_nanos(), Ordering::Release);
+    }
+
+    /// Resets the time back to zero.
+    pub fn reset(&self) {
+        self.offset.store(0, Ordering::Release);
+    }
+
     /// Gets the current value of this `Mock`.
     pub fn value(&self) -> u64 {
         self.offset.load(Ordering::Acquire)
@@ -91,3 +151,39 @@ impl ClockSource for Mock {
         self.now()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_overwrites_absolute_value() {
+        let mock = Mock::new();
+        mock.increment(5u64);
+        mock.set(42u64);
+        assert_eq!(mock.value(), 42);
+    }
+
+    #[test]
+    fn reset_zeroes_the_value() {
+        let mock = Mock::new();
+        mock.set(Duration::from_secs(1));
+        mock.reset();
+        assert_eq!(mock.value(), 0);
+    }
+
+    #[test]
+    fn thread_local_mock_at_zero_is_not_shadowed_by_global() {
+        let installed = Mock::new();
+        installed.set(1_000u64);
+        installed.install_global();
+
+        // A second `Mock` created on this thread becomes *this* thread's mock. Even though
+        // its value is legitimately zero, `Mock::recent()` must report it rather than falling
+        // through to `installed`'s global registration.
+        let _local = Mock::new();
+        assert_eq!(Mock::recent(), 0);
+
+        installed.uninstall_global();
+    }
+}
@@ -0,0 +1,47 @@
+use core::time::Duration;
+
+use crate::macros::impl_duration_arithmetic;
+
+/// A point-in-time monotonic measurement, suitable for durations and benchmarks.
+///
+/// Unlike [`Instant`](crate::Instant), which is scaled to reference time (the Unix epoch) and
+/// is meant for wall-clock use, `Monotonic` carries no such guarantee and no such meaning: it is
+/// only guaranteed to be non-decreasing relative to other `Monotonic` values produced by the
+/// same [`Clock`](crate::Clock). This mirrors the split the standard library already makes
+/// between `std::time::Instant` (monotonic, panics on misuse) and `std::time::SystemTime`
+/// (wall-clock, non-monotonic) -- pick `Monotonic` when you only care about elapsed time, and
+/// [`Instant`](crate::Instant) when you need a value that means something relative to the Unix
+/// epoch.
+///
+/// A `Monotonic` is 8 bytes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Monotonic(pub(crate) u64);
+
+impl_duration_arithmetic!(Monotonic);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_since_and_ordering() {
+        let earlier = Monotonic(100);
+        let later = Monotonic(150);
+        assert_eq!(later.duration_since(earlier), Duration::from_nanos(50));
+        assert!(earlier < later);
+    }
+
+    #[test]
+    fn checked_duration_since_is_none_when_earlier_is_later() {
+        let earlier = Monotonic(100);
+        let later = Monotonic(150);
+        assert_eq!(earlier.checked_duration_since(later), None);
+    }
+
+    #[test]
+    fn saturating_duration_since_floors_at_zero() {
+        let earlier = Monotonic(100);
+        let later = Monotonic(150);
+        assert_eq!(earlier.saturating_duration_since(later), Duration::new(0, 0));
+    }
+}